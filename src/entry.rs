@@ -5,6 +5,13 @@ pub(crate) struct CacheEntry<B> {
     /// Entry value.
     pub(crate) value: B,
 
+    /// Lifetime used to recompute `expiration_time` when the entry is touched:
+    /// the lifetime given at insert or, for an entry restored from a snapshot,
+    /// its remaining lifetime at the time the snapshot was taken.
+    ///
+    /// - [`None`] if the value must be kept forever.
+    lifetime: Option<Duration>,
+
     /// Expiration time.
     ///
     /// - [`None`] if the value must be kept forever.
@@ -15,6 +22,7 @@ impl<B> CacheEntry<B> {
     pub(crate) fn new(value: B, lifetime: Option<Duration>) -> Self {
         Self {
             expiration_time: lifetime.map(|dur| SystemTime::now() + dur),
+            lifetime,
             value,
         }
     }
@@ -22,7 +30,58 @@ impl<B> CacheEntry<B> {
     /// Check if a entry is expired.
     pub(crate) fn is_expired(&self, current_time: SystemTime) -> bool {
         self.expiration_time
-            .map_or(false, |time| current_time >= time)
+            .is_some_and(|time| current_time >= time)
+    }
+
+    /// Renews the entry's expiration deadline to `current_time + lifetime`.
+    ///
+    /// Does nothing for an entry that has no lifetime (kept forever).
+    pub(crate) fn touch(&mut self, current_time: SystemTime) {
+        if let Some(lifetime) = self.lifetime {
+            self.expiration_time = Some(current_time + lifetime);
+        }
+    }
+
+    /// Gets the remaining lifetime relative to `current_time`.
+    ///
+    /// - [`None`] if the value must be kept forever.
+    /// - [`Duration::ZERO`] if the entry is already expired.
+    #[cfg(feature = "serde")]
+    pub(crate) fn remaining(&self, current_time: SystemTime) -> Option<Duration> {
+        self.expiration_time
+            .map(|time| time.duration_since(current_time).unwrap_or_default())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: serde::Serialize> serde::Serialize for CacheEntry<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("CacheEntry", 2)?;
+        state.serialize_field("value", &self.value)?;
+        state.serialize_field("remaining", &self.remaining(SystemTime::now()))?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, B: serde::Deserialize<'de>> serde::Deserialize<'de> for CacheEntry<B> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Raw<B> {
+            value: B,
+            remaining: Option<Duration>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        let now = SystemTime::now();
+
+        Ok(Self {
+            value: raw.value,
+            expiration_time: raw.remaining.map(|remaining| now + remaining),
+            lifetime: raw.remaining,
+        })
     }
 }
 
@@ -33,15 +92,74 @@ mod tests {
     #[test]
     fn is_expired() {
         // Arrange
-        let now = SystemTime::now();
-
         let entry_expired = CacheEntry::new(1, Some(Duration::from_secs(0)));
         let entry_not_expired = CacheEntry::new(1, Some(Duration::from_secs(1)));
         let entry_none_duration = CacheEntry::new(1, None);
 
+        // `now` is captured after construction so it's guaranteed to be at
+        // or past `entry_expired`'s own `SystemTime::now()`-based deadline.
+        let now = SystemTime::now();
+
         // Act and Assert
         assert!(entry_expired.is_expired(now));
         assert!(!entry_not_expired.is_expired(now));
         assert!(!entry_none_duration.is_expired(now));
     }
+
+    #[test]
+    fn touch_renews_expiration_time() {
+        // Arrange
+        let now = SystemTime::now();
+        let mut entry = CacheEntry::new(1, Some(Duration::from_secs(60)));
+
+        // Act
+        entry.touch(now + Duration::from_secs(60));
+
+        // Assert
+        assert!(!entry.is_expired(now + Duration::from_secs(90)));
+        assert!(entry.is_expired(now + Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn touch_does_nothing_without_lifetime() {
+        // Arrange
+        let now = SystemTime::now();
+        let mut entry = CacheEntry::new(1, None);
+
+        // Act
+        entry.touch(now);
+
+        // Assert
+        assert!(!entry.is_expired(now));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_preserves_remaining_lifetime() {
+        // Arrange
+        let now = SystemTime::now();
+        let entry = CacheEntry::new(1, Some(Duration::from_secs(60)));
+
+        // Act
+        let json = serde_json::to_string(&entry).unwrap();
+        let restored: CacheEntry<i32> = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert!(!restored.is_expired(now));
+        assert!(restored.is_expired(now + Duration::from_secs(120)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_keeps_already_expired_entry_expired() {
+        // Arrange
+        let entry = CacheEntry::new(1, Some(Duration::default()));
+
+        // Act
+        let json = serde_json::to_string(&entry).unwrap();
+        let restored: CacheEntry<i32> = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert!(restored.is_expired(SystemTime::now() + Duration::from_millis(1)));
+    }
 }
@@ -0,0 +1,277 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// A single use-count bucket in a [`FrequencyList`], holding every key that has
+/// been accessed exactly `use_count` times.
+struct FrequencyNode<K> {
+    use_count: usize,
+    keys: HashSet<K>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// An O(1) LFU frequency list.
+///
+/// Keys are grouped into [`FrequencyNode`]s ordered by use-count, with a side
+/// `locations` map from key to its current node so that a key can be unlinked
+/// from its node and relinked into the `use_count + 1` node in constant time.
+/// The head of the list always holds the lowest use-count present, so eviction
+/// just pops a key out of the head node.
+pub(crate) struct FrequencyList<K> {
+    nodes: Vec<FrequencyNode<K>>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    locations: HashMap<K, usize>,
+}
+
+impl<K: Hash + Eq + Clone> FrequencyList<K> {
+    pub(crate) fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: None,
+            locations: HashMap::new(),
+        }
+    }
+
+    /// Starts tracking `key` at a use-count of `1`.
+    pub(crate) fn insert(&mut self, key: K) {
+        let node = self.node_at_count(1, None);
+        self.nodes[node].keys.insert(key.clone());
+        self.locations.insert(key, node);
+    }
+
+    /// Moves `key` from its current frequency node to the node for `use_count + 1`.
+    pub(crate) fn touch(&mut self, key: &K) {
+        let current = match self.locations.get(key) {
+            Some(&node) => node,
+            None => return,
+        };
+
+        let use_count = self.nodes[current].use_count;
+        self.nodes[current].keys.remove(key);
+
+        let target = self.node_at_count(use_count + 1, Some(current));
+        self.nodes[target].keys.insert(key.clone());
+        self.locations.insert(key.clone(), target);
+
+        self.remove_node_if_empty(current);
+    }
+
+    /// Returns the number of times `key` has been accessed, or `None` if it
+    /// isn't currently tracked.
+    #[cfg(feature = "serde")]
+    pub(crate) fn use_count(&self, key: &K) -> Option<usize> {
+        self.locations
+            .get(key)
+            .map(|&node| self.nodes[node].use_count)
+    }
+
+    /// Touches `key` until its use-count reaches `target`.
+    ///
+    /// Used to restore a previously saved frequency after reinserting a key
+    /// at the default use-count of `1`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn touch_to(&mut self, key: &K, target: usize) {
+        while self.use_count(key).is_some_and(|count| count < target) {
+            self.touch(key);
+        }
+    }
+
+    /// Stops tracking `key` entirely.
+    pub(crate) fn remove(&mut self, key: &K) {
+        if let Some(node) = self.locations.remove(key) {
+            self.nodes[node].keys.remove(key);
+            self.remove_node_if_empty(node);
+        }
+    }
+
+    /// Removes and returns an arbitrary key from the lowest-frequency node,
+    /// never picking `excluded` even if it is the only key at that frequency
+    /// (the search moves on to the next-lowest node instead).
+    pub(crate) fn pop_least_frequent_except(&mut self, excluded: Option<&K>) -> Option<K> {
+        let mut node = self.head;
+
+        while let Some(index) = node {
+            let key = self.nodes[index]
+                .keys
+                .iter()
+                .find(|key| Some(*key) != excluded)
+                .cloned();
+
+            if let Some(key) = key {
+                self.nodes[index].keys.remove(&key);
+                self.locations.remove(&key);
+                self.remove_node_if_empty(index);
+
+                return Some(key);
+            }
+
+            node = self.nodes[index].next;
+        }
+
+        None
+    }
+
+    /// Returns the node for `use_count`, creating and linking it right after
+    /// `after` (or as the new head, if `after` is `None`) if it doesn't exist yet.
+    fn node_at_count(&mut self, use_count: usize, after: Option<usize>) -> usize {
+        let next = match after {
+            Some(after) => self.nodes[after].next,
+            None => self.head,
+        };
+
+        if let Some(existing) = next {
+            if self.nodes[existing].use_count == use_count {
+                return existing;
+            }
+        }
+
+        let node = FrequencyNode {
+            use_count,
+            keys: HashSet::new(),
+            prev: after,
+            next,
+        };
+
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+
+        match after {
+            Some(after) => self.nodes[after].next = Some(index),
+            None => self.head = Some(index),
+        }
+
+        if let Some(next) = next {
+            self.nodes[next].prev = Some(index);
+        }
+
+        index
+    }
+
+    fn remove_node_if_empty(&mut self, node: usize) {
+        if !self.nodes[node].keys.is_empty() {
+            return;
+        }
+
+        let prev = self.nodes[node].prev;
+        let next = self.nodes[node].next;
+
+        match prev {
+            Some(prev) => self.nodes[prev].next = next,
+            None => self.head = next,
+        }
+
+        if let Some(next) = next {
+            self.nodes[next].prev = prev;
+        }
+
+        self.free.push(node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pop_least_frequent_prefers_untouched_keys() {
+        // Arrange
+        let mut frequency_list = FrequencyList::new();
+        frequency_list.insert("a");
+        frequency_list.insert("b");
+        frequency_list.touch(&"a");
+
+        // Act and Assert
+        assert_eq!(frequency_list.pop_least_frequent_except(None), Some("b"));
+        assert_eq!(frequency_list.pop_least_frequent_except(None), Some("a"));
+        assert_eq!(frequency_list.pop_least_frequent_except(None), None);
+    }
+
+    #[test]
+    fn pop_least_frequent_except_skips_excluded_key_at_the_same_frequency() {
+        // Arrange
+        let mut frequency_list = FrequencyList::new();
+        frequency_list.insert("a");
+        frequency_list.insert("b");
+
+        // Act and Assert
+        assert_eq!(
+            frequency_list.pop_least_frequent_except(Some(&"a")),
+            Some("b")
+        );
+        assert_eq!(frequency_list.pop_least_frequent_except(Some(&"a")), None);
+    }
+
+    #[test]
+    fn pop_least_frequent_except_falls_through_to_the_next_frequency() {
+        // Arrange
+        let mut frequency_list = FrequencyList::new();
+        frequency_list.insert("a");
+        frequency_list.insert("b");
+        frequency_list.touch(&"b");
+
+        // Act and Assert: "a" is the only key left at the lowest frequency,
+        // but it's excluded, so the next-lowest ("b") is picked instead.
+        assert_eq!(
+            frequency_list.pop_least_frequent_except(Some(&"a")),
+            Some("b")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn use_count_reflects_touches() {
+        // Arrange
+        let mut frequency_list = FrequencyList::new();
+        frequency_list.insert("a");
+        frequency_list.touch(&"a");
+        frequency_list.touch(&"a");
+
+        // Act and Assert
+        assert_eq!(frequency_list.use_count(&"a"), Some(3));
+        assert_eq!(frequency_list.use_count(&"b"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn touch_to_restores_a_saved_use_count() {
+        // Arrange
+        let mut frequency_list = FrequencyList::new();
+        frequency_list.insert("a");
+        frequency_list.insert("b");
+
+        // Act
+        frequency_list.touch_to(&"a", 3);
+
+        // Assert
+        assert_eq!(frequency_list.use_count(&"a"), Some(3));
+        assert_eq!(
+            frequency_list.pop_least_frequent_except(None),
+            Some("b")
+        );
+    }
+
+    #[test]
+    fn remove_stops_tracking_a_key() {
+        // Arrange
+        let mut frequency_list = FrequencyList::new();
+        frequency_list.insert("a");
+        frequency_list.insert("b");
+
+        // Act
+        frequency_list.remove(&"a");
+
+        // Assert
+        assert_eq!(frequency_list.pop_least_frequent_except(None), Some("b"));
+        assert_eq!(frequency_list.pop_least_frequent_except(None), None);
+    }
+}
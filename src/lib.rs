@@ -1,21 +1,62 @@
 mod entry;
+mod lfu;
 pub mod macros;
+#[cfg(feature = "sync")]
+mod sync;
+
+#[cfg(feature = "sync")]
+pub use crate::sync::SyncMemoryCache;
 
 use crate::entry::*;
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use crate::lfu::FrequencyList;
+use hashlink::linked_hash_map::Entry;
+use hashlink::LinkedHashMap;
 use std::hash::Hash;
 use std::time::{Duration, SystemTime};
 
+/// Selects how a capacity-bounded `MemoryCache` chooses an eviction victim.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum EvictionPolicy {
+    /// The cache is unbounded; no eviction takes place.
+    None,
+
+    /// Evict the least-recently-used entry.
+    Lru,
+
+    /// Evict the least-frequently-used entry.
+    Lfu,
+}
+
+/// Determines how an entry's expiration deadline behaves over its lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Expiration {
+    /// The entry expires at a fixed deadline set when it was inserted.
+    Absolute,
+
+    /// A successful `get`/`get_or_insert` renews the entry's deadline to `now + lifetime`.
+    Sliding,
+}
+
 /// Represents a local in-memory cache.
 pub struct MemoryCache<A, B> {
-    cache_table: HashMap<A, CacheEntry<B>>,
+    cache_table: LinkedHashMap<A, CacheEntry<B>>,
     full_scan_frequency: Option<Duration>,
     created_time: SystemTime,
     last_scan_time: Option<SystemTime>,
+    capacity: Option<usize>,
+    expiration: Expiration,
+    eviction_policy: EvictionPolicy,
+    frequency_list: Option<FrequencyList<A>>,
+}
+
+impl<A: Hash + Eq + Clone, B> Default for MemoryCache<A, B> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl<A: Hash + Eq, B> MemoryCache<A, B> {
+impl<A: Hash + Eq + Clone, B> MemoryCache<A, B> {
     /// Creates an empty `MemoryCache`.
     ///
     /// # Example
@@ -34,10 +75,14 @@ impl<A: Hash + Eq, B> MemoryCache<A, B> {
     /// ```
     pub fn new() -> Self {
         Self {
-            cache_table: HashMap::new(),
+            cache_table: LinkedHashMap::new(),
             full_scan_frequency: None,
             created_time: SystemTime::now(),
             last_scan_time: None,
+            capacity: None,
+            expiration: Expiration::Absolute,
+            eviction_policy: EvictionPolicy::None,
+            frequency_list: None,
         }
     }
 
@@ -61,10 +106,102 @@ impl<A: Hash + Eq, B> MemoryCache<A, B> {
     /// ```
     pub fn with_full_scan(full_scan_frequency: Duration) -> Self {
         Self {
-            cache_table: HashMap::new(),
+            cache_table: LinkedHashMap::new(),
             full_scan_frequency: Some(full_scan_frequency),
             created_time: SystemTime::now(),
             last_scan_time: None,
+            capacity: None,
+            expiration: Expiration::Absolute,
+            eviction_policy: EvictionPolicy::None,
+            frequency_list: None,
+        }
+    }
+
+    /// Creates an empty `MemoryCache` that evicts the least-recently-used entry
+    /// once the number of entries exceeds `max_entries`.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let mut cache = MemoryCache::with_capacity(1);
+    ///
+    /// cache.insert("a", 1, None);
+    /// cache.insert("b", 2, None);
+    ///
+    /// assert!(!cache.contains_key(&"a"));
+    /// assert!(cache.contains_key(&"b"));
+    /// ```
+    pub fn with_capacity(max_entries: usize) -> Self {
+        Self {
+            cache_table: LinkedHashMap::new(),
+            full_scan_frequency: None,
+            created_time: SystemTime::now(),
+            last_scan_time: None,
+            capacity: Some(max_entries),
+            expiration: Expiration::Absolute,
+            eviction_policy: EvictionPolicy::Lru,
+            frequency_list: None,
+        }
+    }
+
+    /// Creates an empty `MemoryCache` that combines a periodic full scan with
+    /// least-recently-used eviction once the number of entries exceeds `max_entries`.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    /// use std::time::Duration;
+    ///
+    /// let scan_frequency = Duration::from_secs(60);
+    ///
+    /// let mut cache = MemoryCache::with_full_scan_and_capacity(scan_frequency, 1);
+    ///
+    /// cache.insert("a", 1, None);
+    /// cache.insert("b", 2, None);
+    ///
+    /// assert!(!cache.contains_key(&"a"));
+    /// assert!(cache.contains_key(&"b"));
+    /// ```
+    pub fn with_full_scan_and_capacity(full_scan_frequency: Duration, max_entries: usize) -> Self {
+        Self {
+            cache_table: LinkedHashMap::new(),
+            full_scan_frequency: Some(full_scan_frequency),
+            created_time: SystemTime::now(),
+            last_scan_time: None,
+            capacity: Some(max_entries),
+            expiration: Expiration::Absolute,
+            eviction_policy: EvictionPolicy::Lru,
+            frequency_list: None,
+        }
+    }
+
+    /// Creates an empty `MemoryCache` that evicts the least-frequently-used entry
+    /// once the number of entries exceeds `max_entries`.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let mut cache = MemoryCache::with_lfu(1);
+    ///
+    /// cache.insert("a", 1, None);
+    /// cache.get(&"a");
+    /// cache.insert("b", 2, None);
+    ///
+    /// assert!(cache.contains_key(&"a"));
+    /// assert!(!cache.contains_key(&"b"));
+    /// ```
+    pub fn with_lfu(max_entries: usize) -> Self {
+        Self {
+            cache_table: LinkedHashMap::new(),
+            full_scan_frequency: None,
+            created_time: SystemTime::now(),
+            last_scan_time: None,
+            capacity: Some(max_entries),
+            expiration: Expiration::Absolute,
+            eviction_policy: EvictionPolicy::Lfu,
+            frequency_list: Some(FrequencyList::new()),
         }
     }
 
@@ -93,6 +230,80 @@ impl<A: Hash + Eq, B> MemoryCache<A, B> {
             .is_some()
     }
 
+    /// Gets the number of entries currently stored in the cache, including any
+    /// that have expired but have not yet been purged by a full scan, `retain`,
+    /// capacity eviction, or an `insert`/`get_or_insert`/`remove` of the same key.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let mut cache = MemoryCache::new();
+    ///
+    /// cache.insert("key", "Hello, World!", None);
+    ///
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.cache_table.len()
+    }
+
+    /// Determines whether the cache contains no entries.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let cache: MemoryCache<&str, i32> = MemoryCache::new();
+    ///
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.cache_table.is_empty()
+    }
+
+    /// Removes all entries from the cache.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let mut cache = MemoryCache::new();
+    ///
+    /// cache.insert("key", "Hello, World!", None);
+    /// cache.clear();
+    ///
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.cache_table.clear();
+
+        if self.frequency_list.is_some() {
+            self.frequency_list = Some(FrequencyList::new());
+        }
+    }
+
+    /// Returns an iterator over the non-expired `(&A, &B)` pairs in the cache.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let mut cache = MemoryCache::new();
+    ///
+    /// cache.insert("key", "Hello, World!", None);
+    ///
+    /// assert_eq!(cache.iter().collect::<Vec<_>>(), vec![(&"key", &"Hello, World!")]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&A, &B)> {
+        let now = SystemTime::now();
+
+        self.cache_table
+            .iter()
+            .filter(move |(_, cache_entry)| !cache_entry.is_expired(now))
+            .map(|(key, cache_entry)| (key, &cache_entry.value))
+    }
+
     /// Gets the last scan time.
     ///
     /// - [`None`] If there were no scans.
@@ -137,8 +348,59 @@ impl<A: Hash + Eq, B> MemoryCache<A, B> {
         self.full_scan_frequency
     }
 
+    /// Gets the maximum number of entries the cache will hold before evicting
+    /// the least-recently-used (or, for an LFU cache, least-frequently-used) entry.
+    ///
+    /// - [`None`] if the cache is unbounded.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let cache: MemoryCache<&str, i32> = MemoryCache::with_capacity(128);
+    ///
+    /// assert_eq!(cache.get_capacity(), Some(128));
+    /// ```
+    pub fn get_capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Gets the expiration mode.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::{Expiration, MemoryCache};
+    ///
+    /// let cache: MemoryCache<&str, i32> = MemoryCache::new().with_sliding_expiration();
+    ///
+    /// assert_eq!(cache.get_expiration(), Expiration::Sliding);
+    /// ```
+    pub fn get_expiration(&self) -> Expiration {
+        self.expiration
+    }
+
+    /// Switches the cache to sliding expiration: a successful `get`/`get_or_insert`
+    /// renews an entry's deadline to `now + lifetime` instead of letting it expire
+    /// at a fixed point in time.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let cache: MemoryCache<&str, i32> = MemoryCache::new().with_sliding_expiration();
+    /// ```
+    pub fn with_sliding_expiration(mut self) -> Self {
+        self.expiration = Expiration::Sliding;
+        self
+    }
+
     /// Gets the value associated with the specified key.
     ///
+    /// In an LRU cache, a successful lookup moves the key to the
+    /// most-recently-used position; in an LFU cache, it increments the key's
+    /// use-count. In sliding expiration mode, a successful lookup also renews
+    /// the entry's deadline.
+    ///
     /// # Example
     /// ```
     /// use memory_cache::MemoryCache;
@@ -153,12 +415,40 @@ impl<A: Hash + Eq, B> MemoryCache<A, B> {
     ///
     /// assert_eq!(cache.get(&key), Some(&value));
     /// ```
-    pub fn get(&self, key: &A) -> Option<&B> {
+    pub fn get(&mut self, key: &A) -> Option<&B> {
         let now = SystemTime::now();
 
+        let is_expired = self
+            .cache_table
+            .get(key)
+            .is_some_and(|cache_entry| cache_entry.is_expired(now));
+
+        if is_expired {
+            return None;
+        }
+
+        if self.cache_table.contains_key(key) {
+            match self.eviction_policy {
+                EvictionPolicy::Lru => {
+                    self.cache_table.to_back(key);
+                }
+                EvictionPolicy::Lfu => {
+                    if let Some(frequency_list) = &mut self.frequency_list {
+                        frequency_list.touch(key);
+                    }
+                }
+                EvictionPolicy::None => {}
+            }
+        }
+
+        if let Some(cache_entry) = self.cache_table.get_mut(key) {
+            if self.expiration == Expiration::Sliding {
+                cache_entry.touch(now);
+            }
+        }
+
         self.cache_table
-            .get(&key)
-            .filter(|cache_entry| !cache_entry.is_expired(now))
+            .get(key)
             .map(|cache_entry| &cache_entry.value)
     }
 
@@ -186,21 +476,48 @@ impl<A: Hash + Eq, B> MemoryCache<A, B> {
 
         self.try_full_scan_expired_items(now);
 
-        match self.cache_table.entry(key) {
+        match self.cache_table.entry(key.clone()) {
             Entry::Occupied(mut occupied) => {
                 if occupied.get().is_expired(now) {
                     occupied.insert(CacheEntry::new(factory(), lifetime));
+
+                    if let Some(frequency_list) = &mut self.frequency_list {
+                        frequency_list.remove(&key);
+                        frequency_list.insert(key.clone());
+                    }
+                } else {
+                    if self.expiration == Expiration::Sliding {
+                        occupied.get_mut().touch(now);
+                    }
+
+                    if let EvictionPolicy::Lfu = self.eviction_policy {
+                        if let Some(frequency_list) = &mut self.frequency_list {
+                            frequency_list.touch(&key);
+                        }
+                    }
                 }
+            }
+            Entry::Vacant(vacant) => {
+                vacant.insert(CacheEntry::new(factory(), lifetime));
 
-                &occupied.into_mut().value
+                if let Some(frequency_list) = &mut self.frequency_list {
+                    frequency_list.insert(key.clone());
+                }
             }
-            Entry::Vacant(vacant) => &vacant.insert(CacheEntry::new(factory(), lifetime)).value,
         }
+
+        if let EvictionPolicy::Lru = self.eviction_policy {
+            self.cache_table.to_back(&key);
+        }
+
+        self.evict_if_over_capacity(now, Some(&key));
+
+        &self.cache_table.get(&key).unwrap().value
     }
 
     /// Inserts a key-value pair into the cache.
     ///
-    /// If the cache did not have this key present, `None` is returned.  
+    /// If the cache did not have this key present, `None` is returned.
     /// If the cache did have this key present, the value is updated, and the old value is returned.
     ///
     /// # Example
@@ -222,10 +539,24 @@ impl<A: Hash + Eq, B> MemoryCache<A, B> {
 
         self.try_full_scan_expired_items(now);
 
-        self.cache_table
-            .insert(key, CacheEntry::new(value, lifetime))
+        let old_value = self
+            .cache_table
+            .insert(key.clone(), CacheEntry::new(value, lifetime))
             .filter(|cache_entry| !cache_entry.is_expired(now))
-            .map(|cache_entry| cache_entry.value)
+            .map(|cache_entry| cache_entry.value);
+
+        if let Some(frequency_list) = &mut self.frequency_list {
+            frequency_list.remove(&key);
+            frequency_list.insert(key.clone());
+        }
+
+        if let EvictionPolicy::Lru = self.eviction_policy {
+            self.cache_table.to_back(&key);
+        }
+
+        self.evict_if_over_capacity(now, None);
+
+        old_value
     }
 
     /// Removes a key from the cache, returning the value at the key if the key was previously in the cache.
@@ -250,10 +581,59 @@ impl<A: Hash + Eq, B> MemoryCache<A, B> {
 
         self.try_full_scan_expired_items(now);
 
-        self.cache_table
+        let removed = self
+            .cache_table
             .remove(key)
             .filter(|cache_entry| !cache_entry.is_expired(now))
-            .map(|cache_entry| cache_entry.value)
+            .map(|cache_entry| cache_entry.value);
+
+        if let Some(frequency_list) = &mut self.frequency_list {
+            frequency_list.remove(key);
+        }
+
+        removed
+    }
+
+    /// Drops all expired entries, then keeps only the remaining entries for
+    /// which `f` returns `true`.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let mut cache = MemoryCache::new();
+    ///
+    /// cache.insert("tenant-1:a", 1, None);
+    /// cache.insert("tenant-1:b", 2, None);
+    /// cache.insert("tenant-2:a", 3, None);
+    ///
+    /// cache.retain(|key, _| !key.starts_with("tenant-1:"));
+    ///
+    /// assert_eq!(cache.len(), 1);
+    /// assert!(cache.contains_key(&"tenant-2:a"));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&A, &B) -> bool,
+    {
+        let now = SystemTime::now();
+
+        let removed_keys: Vec<A> = self
+            .cache_table
+            .iter()
+            .filter(|(key, cache_entry)| {
+                cache_entry.is_expired(now) || !f(key, &cache_entry.value)
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in &removed_keys {
+            self.cache_table.remove(key);
+
+            if let Some(frequency_list) = &mut self.frequency_list {
+                frequency_list.remove(key);
+            }
+        }
     }
 
     fn try_full_scan_expired_items(&mut self, current_time: SystemTime) {
@@ -270,6 +650,237 @@ impl<A: Hash + Eq, B> MemoryCache<A, B> {
             }
         }
     }
+
+    /// Evicts entries once the cache grows past its configured capacity.
+    ///
+    /// Expired entries are evicted first; if none are expired, the eviction
+    /// policy's victim is popped (the front of `cache_table` for LRU, or the
+    /// lowest-frequency key for LFU). `protected_key`, if given, is never
+    /// evicted, so a caller that is about to return a reference to that
+    /// key's value is guaranteed to still find it afterwards.
+    fn evict_if_over_capacity(&mut self, current_time: SystemTime, protected_key: Option<&A>) {
+        let capacity = match self.capacity {
+            Some(capacity) => capacity,
+            None => return,
+        };
+
+        while self.cache_table.len() > capacity {
+            let expired_key = self
+                .cache_table
+                .iter()
+                .find(|(key, cache_entry)| {
+                    cache_entry.is_expired(current_time) && Some(*key) != protected_key
+                })
+                .map(|(key, _)| key.clone());
+
+            if let Some(key) = expired_key {
+                self.cache_table.remove(&key);
+
+                if let Some(frequency_list) = &mut self.frequency_list {
+                    frequency_list.remove(&key);
+                }
+
+                continue;
+            }
+
+            let victim = match self.eviction_policy {
+                EvictionPolicy::Lfu => self
+                    .frequency_list
+                    .as_mut()
+                    .and_then(|frequency_list| {
+                        frequency_list.pop_least_frequent_except(protected_key)
+                    })
+                    .or_else(|| {
+                        self.cache_table
+                            .iter()
+                            .find(|(key, _)| Some(*key) != protected_key)
+                            .map(|(key, _)| key.clone())
+                    }),
+                EvictionPolicy::Lru | EvictionPolicy::None => self
+                    .cache_table
+                    .iter()
+                    .find(|(key, _)| Some(*key) != protected_key)
+                    .map(|(key, _)| key.clone()),
+            };
+
+            match victim {
+                Some(key) => {
+                    self.cache_table.remove(&key);
+
+                    if let Some(frequency_list) = &mut self.frequency_list {
+                        frequency_list.remove(&key);
+                    }
+                }
+                // Only the protected key is left; nothing else can be evicted.
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A, B> MemoryCache<A, B>
+where
+    A: Hash + Eq + Clone + serde::Serialize + serde::de::DeserializeOwned,
+    B: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Saves a snapshot of the cache to `writer` as JSON: its configuration
+    /// (full scan frequency, capacity, expiration mode, eviction policy) plus
+    /// the non-expired entries, recording each entry's remaining lifetime
+    /// rather than its absolute expiration time. Entries are written in
+    /// `cache_table` order with their LFU use-count (if any), so
+    /// [`MemoryCache::load_from`] can restore the cache's eviction order, not
+    /// just its configuration.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let mut cache = MemoryCache::new();
+    /// cache.insert("key".to_string(), "Hello, World!".to_string(), None);
+    ///
+    /// let mut buffer = Vec::new();
+    /// cache.save_to(&mut buffer).unwrap();
+    /// ```
+    pub fn save_to<W: std::io::Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Restores a cache previously written by [`MemoryCache::save_to`], including
+    /// its capacity, eviction policy, expiration mode, LRU recency order and
+    /// LFU use-counts.
+    ///
+    /// Entries are reinserted with `expiration_time = now + remaining`, so an
+    /// entry that was already expired when saved is dropped instead of being restored.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::MemoryCache;
+    ///
+    /// let mut cache = MemoryCache::new();
+    /// cache.insert("key".to_string(), "Hello, World!".to_string(), None);
+    ///
+    /// let mut buffer = Vec::new();
+    /// cache.save_to(&mut buffer).unwrap();
+    ///
+    /// let mut restored: MemoryCache<String, String> =
+    ///     MemoryCache::load_from(buffer.as_slice()).unwrap();
+    ///
+    /// assert_eq!(restored.get(&"key".to_string()), Some(&"Hello, World!".to_string()));
+    /// ```
+    pub fn load_from<R: std::io::Read>(reader: R) -> serde_json::Result<Self> {
+        serde_json::from_reader(reader)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A, B> serde::Serialize for MemoryCache<A, B>
+where
+    A: Hash + Eq + Clone + serde::Serialize,
+    B: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        #[derive(serde::Serialize)]
+        struct SerializedEntry<'a, A, B> {
+            key: &'a A,
+            entry: &'a CacheEntry<B>,
+            /// The entry's LFU use-count, or `None` outside LFU caches.
+            frequency: Option<usize>,
+        }
+
+        let now = SystemTime::now();
+        let entries: Vec<SerializedEntry<A, B>> = self
+            .cache_table
+            .iter()
+            .filter(|(_, cache_entry)| !cache_entry.is_expired(now))
+            .map(|(key, entry)| SerializedEntry {
+                key,
+                entry,
+                frequency: self
+                    .frequency_list
+                    .as_ref()
+                    .and_then(|frequency_list| frequency_list.use_count(key)),
+            })
+            .collect();
+
+        let mut state = serializer.serialize_struct("MemoryCache", 5)?;
+        state.serialize_field("full_scan_frequency", &self.full_scan_frequency)?;
+        state.serialize_field("capacity", &self.capacity)?;
+        state.serialize_field("expiration", &self.expiration)?;
+        state.serialize_field("eviction_policy", &self.eviction_policy)?;
+        state.serialize_field("entries", &entries)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A, B> serde::Deserialize<'de> for MemoryCache<A, B>
+where
+    A: Hash + Eq + Clone + serde::Deserialize<'de>,
+    B: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(bound(
+            deserialize = "A: Hash + Eq + serde::Deserialize<'de>, B: serde::Deserialize<'de>"
+        ))]
+        struct RawEntry<A, B> {
+            key: A,
+            entry: CacheEntry<B>,
+            frequency: Option<usize>,
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(bound(
+            deserialize = "A: Hash + Eq + serde::Deserialize<'de>, B: serde::Deserialize<'de>"
+        ))]
+        struct Raw<A: Hash + Eq, B> {
+            full_scan_frequency: Option<Duration>,
+            capacity: Option<usize>,
+            expiration: Expiration,
+            eviction_policy: EvictionPolicy,
+            entries: Vec<RawEntry<A, B>>,
+        }
+
+        let raw: Raw<A, B> = Raw::deserialize(deserializer)?;
+        let now = SystemTime::now();
+
+        let mut cache = Self {
+            cache_table: LinkedHashMap::new(),
+            full_scan_frequency: raw.full_scan_frequency,
+            created_time: now,
+            last_scan_time: None,
+            capacity: raw.capacity,
+            expiration: raw.expiration,
+            frequency_list: match raw.eviction_policy {
+                EvictionPolicy::Lfu => Some(FrequencyList::new()),
+                EvictionPolicy::None | EvictionPolicy::Lru => None,
+            },
+            eviction_policy: raw.eviction_policy,
+        };
+
+        // Reinserted in the saved `cache_table` order, so LRU recency is
+        // restored rather than reshuffled; each key's LFU use-count (if any)
+        // is then restored on top of the use-count of `1` that `insert` sets.
+        for raw_entry in raw.entries {
+            if raw_entry.entry.is_expired(now) {
+                continue;
+            }
+
+            let remaining = raw_entry.entry.remaining(now);
+            cache.insert(raw_entry.key.clone(), raw_entry.entry.value, remaining);
+
+            if let (Some(frequency_list), Some(use_count)) =
+                (&mut cache.frequency_list, raw_entry.frequency)
+            {
+                frequency_list.touch_to(&raw_entry.key, use_count);
+            }
+        }
+
+        Ok(cache)
+    }
 }
 
 #[cfg(test)]
@@ -361,4 +972,302 @@ mod tests {
         // Assert
         assert!(last_scan_time.is_some())
     }
+
+    #[test]
+    fn evicts_least_recently_used_entry_over_capacity() {
+        // Arrange
+        let mut cache = MemoryCache::with_capacity(2);
+
+        // Act
+        cache.insert("a", 1, None);
+        cache.insert("b", 2, None);
+        cache.get(&"a");
+        cache.insert("c", 3, None);
+
+        // Assert
+        assert!(cache.contains_key(&"a"));
+        assert!(!cache.contains_key(&"b"));
+        assert!(cache.contains_key(&"c"));
+    }
+
+    #[test]
+    fn get_or_insert_with_zero_capacity_does_not_panic() {
+        // Arrange
+        let mut cache = MemoryCache::with_capacity(0);
+
+        // Act and Assert: the entry `get_or_insert` just created is its own
+        // only candidate for eviction, so it must not be evicted out from
+        // under the reference being returned.
+        assert_eq!(cache.get_or_insert("a", || 1, None), &1);
+        assert_eq!(cache.get_or_insert("b", || 2, None), &2);
+    }
+
+    #[test]
+    fn evicts_expired_entry_before_lru_victim() {
+        // Arrange
+        let mut cache = MemoryCache::with_capacity(2);
+
+        // Act: "b" is the least-recently-used entry, but "a" is expired.
+        cache.insert("b", 2, None);
+        cache.insert("a", 1, Some(Duration::default()));
+        cache.insert("c", 3, None);
+
+        // Assert
+        assert!(!cache.contains_key(&"a"));
+        assert!(cache.contains_key(&"b"));
+        assert!(cache.contains_key(&"c"));
+    }
+
+    #[test]
+    fn sliding_expiration_renews_on_get() {
+        // Arrange
+        let mut cache = MemoryCache::new().with_sliding_expiration();
+        let key: &'static str = "key";
+
+        // Act and Assert
+        cache.insert(key, 1, Some(Duration::from_millis(50)));
+        std::thread::sleep(Duration::from_millis(25));
+        assert_eq!(cache.get(&key), Some(&1));
+
+        std::thread::sleep(Duration::from_millis(25));
+        assert_eq!(cache.get(&key), Some(&1));
+    }
+
+    #[test]
+    fn absolute_expiration_does_not_renew_on_get() {
+        // Arrange
+        let mut cache = MemoryCache::new();
+        let key: &'static str = "key";
+
+        // Act
+        cache.insert(key, 1, Some(Duration::from_millis(50)));
+        std::thread::sleep(Duration::from_millis(25));
+        cache.get(&key);
+        std::thread::sleep(Duration::from_millis(25));
+
+        // Assert
+        assert_eq!(cache.get(&key), None);
+    }
+
+    #[test]
+    fn evicts_least_frequently_used_entry_over_capacity() {
+        // Arrange
+        let mut cache = MemoryCache::with_lfu(1);
+
+        // Act
+        cache.insert("a", 1, None);
+        cache.get(&"a");
+        cache.insert("b", 2, None);
+
+        // Assert
+        assert!(cache.contains_key(&"a"));
+        assert!(!cache.contains_key(&"b"));
+    }
+
+    #[test]
+    fn get_or_insert_lfu_does_not_evict_the_key_it_just_inserted() {
+        // Arrange: "a" and the about-to-be-inserted "b" are both candidates
+        // at use-count 1, so the LFU victim search must exclude "b" even
+        // though it's tied with "a" for lowest frequency.
+        let mut cache = MemoryCache::with_lfu(1);
+        cache.insert("a", 1, None);
+
+        // Act and Assert
+        assert_eq!(cache.get_or_insert("b", || 2, None), &2);
+    }
+
+    #[test]
+    fn len_and_is_empty() {
+        // Arrange
+        let mut cache = MemoryCache::new();
+
+        // Act and Assert
+        assert!(cache.is_empty());
+        assert_eq!(cache.len(), 0);
+
+        cache.insert("key", 1, None);
+
+        assert!(!cache.is_empty());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn clear_removes_all_entries() {
+        // Arrange
+        let mut cache = MemoryCache::new();
+        cache.insert("a", 1, None);
+        cache.insert("b", 2, None);
+
+        // Act
+        cache.clear();
+
+        // Assert
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn iter_skips_expired_entries() {
+        // Arrange
+        let mut cache = MemoryCache::new();
+        cache.insert("a", 1, Some(Duration::default()));
+        cache.insert("b", 2, None);
+
+        // Act
+        let entries: Vec<_> = cache.iter().collect();
+
+        // Assert
+        assert_eq!(entries, vec![(&"b", &2)]);
+    }
+
+    #[test]
+    fn retain_keeps_only_matching_entries() {
+        // Arrange
+        let mut cache = MemoryCache::new();
+        cache.insert("tenant-1:a", 1, None);
+        cache.insert("tenant-1:b", 2, Some(Duration::default()));
+        cache.insert("tenant-2:a", 3, None);
+
+        // Act
+        cache.retain(|key, _| !key.starts_with("tenant-1:"));
+
+        // Assert
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains_key(&"tenant-2:a"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn save_to_and_load_from_roundtrip() {
+        // Arrange
+        let mut cache = MemoryCache::new();
+        cache.insert("key".to_string(), "Hello, World!".to_string(), None);
+
+        let mut buffer = Vec::new();
+        cache.save_to(&mut buffer).unwrap();
+
+        // Act
+        let mut restored: MemoryCache<String, String> =
+            MemoryCache::load_from(buffer.as_slice()).unwrap();
+
+        // Assert
+        assert_eq!(
+            restored.get(&"key".to_string()),
+            Some(&"Hello, World!".to_string())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn load_from_drops_already_expired_entries() {
+        // Arrange
+        let mut cache = MemoryCache::new();
+        cache.insert(
+            "key".to_string(),
+            "Hello, World!".to_string(),
+            Some(Duration::default()),
+        );
+
+        let mut buffer = Vec::new();
+        cache.save_to(&mut buffer).unwrap();
+
+        // Act
+        let restored: MemoryCache<String, String> =
+            MemoryCache::load_from(buffer.as_slice()).unwrap();
+
+        // Assert
+        assert!(restored.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn save_to_and_load_from_preserves_capacity() {
+        // Arrange
+        let mut cache = MemoryCache::with_capacity(2);
+        cache.insert("a".to_string(), 1, None);
+        cache.insert("b".to_string(), 2, None);
+
+        let mut buffer = Vec::new();
+        cache.save_to(&mut buffer).unwrap();
+
+        // Act
+        let mut restored: MemoryCache<String, i32> =
+            MemoryCache::load_from(buffer.as_slice()).unwrap();
+        restored.insert("c".to_string(), 3, None);
+        restored.insert("d".to_string(), 4, None);
+
+        // Assert
+        assert_eq!(restored.get_capacity(), Some(2));
+        assert_eq!(restored.len(), 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn save_to_and_load_from_preserves_lfu_eviction_policy() {
+        // Arrange
+        let mut cache = MemoryCache::with_lfu(1);
+        cache.insert("a".to_string(), 1, None);
+        cache.get(&"a".to_string());
+
+        let mut buffer = Vec::new();
+        cache.save_to(&mut buffer).unwrap();
+
+        // Act
+        let mut restored: MemoryCache<String, i32> =
+            MemoryCache::load_from(buffer.as_slice()).unwrap();
+        restored.insert("b".to_string(), 2, None);
+
+        // Assert: LFU evicts the less frequently used "b"; LRU would have
+        // evicted the older "a" instead.
+        assert_eq!(restored.len(), 1);
+        assert!(restored.contains_key(&"a".to_string()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn save_to_and_load_from_preserves_lru_recency_order() {
+        // Arrange
+        let mut cache = MemoryCache::with_capacity(2);
+        cache.insert("a".to_string(), 1, None);
+        cache.insert("b".to_string(), 2, None);
+        cache.get(&"a".to_string());
+
+        let mut buffer = Vec::new();
+        cache.save_to(&mut buffer).unwrap();
+
+        // Act
+        let mut restored: MemoryCache<String, i32> =
+            MemoryCache::load_from(buffer.as_slice()).unwrap();
+        restored.insert("c".to_string(), 3, None);
+
+        // Assert: "b" was the least-recently-used entry before saving, so
+        // it's the one evicted once the restored cache is over capacity again.
+        assert!(restored.contains_key(&"a".to_string()));
+        assert!(!restored.contains_key(&"b".to_string()));
+        assert!(restored.contains_key(&"c".to_string()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn save_to_and_load_from_preserves_lfu_use_counts() {
+        // Arrange
+        let mut cache = MemoryCache::with_lfu(2);
+        cache.insert("a".to_string(), 1, None);
+        cache.insert("b".to_string(), 2, None);
+        cache.get(&"a".to_string());
+
+        let mut buffer = Vec::new();
+        cache.save_to(&mut buffer).unwrap();
+
+        // Act: `get_or_insert` excludes the key it's adding from its own
+        // eviction, so the victim is chosen strictly between the two
+        // restored entries, based on their restored use-counts.
+        let mut restored: MemoryCache<String, i32> =
+            MemoryCache::load_from(buffer.as_slice()).unwrap();
+        restored.get_or_insert("c".to_string(), || 3, None);
+
+        // Assert: "b" has the lowest saved use-count, so it's evicted, not "a".
+        assert!(restored.contains_key(&"a".to_string()));
+        assert!(!restored.contains_key(&"b".to_string()));
+        assert!(restored.contains_key(&"c".to_string()));
+    }
 }
@@ -1,5 +1,9 @@
 /// Macro for defining functions whose result needs to be cached.
 ///
+/// By default the cache keeps every result forever and grows without bound.
+/// Attach a `#[ttl(..)]` and/or `#[capacity(..)]` attribute to the function to
+/// back it with an expiring and/or capacity-bounded [`MemoryCache`] instead.
+///
 /// # Example
 /// ```
 /// use once_cell::sync::Lazy;
@@ -18,20 +22,71 @@
 ///
 /// assert_eq!(factorial(21), 51090942171709440000);
 /// ```
+///
+/// # Example (expiring entries)
+/// ```
+/// use once_cell::sync::Lazy;
+/// use std::sync::Mutex;
+/// use std::time::Duration;
+/// use memory_cache::{MemoryCache, cached};
+///
+/// cached! {
+///     #[ttl(Duration::from_secs(60))]
+///     fn square(x: u128) -> u128 = { x * x }
+/// }
+///
+/// assert_eq!(square(4), 16);
+/// ```
+///
+/// # Example (capacity-bounded entries)
+/// ```
+/// use once_cell::sync::Lazy;
+/// use std::sync::Mutex;
+/// use memory_cache::{MemoryCache, cached};
+///
+/// cached! {
+///     #[capacity(1000)]
+///     fn cube(x: u128) -> u128 = { x * x * x }
+/// }
+///
+/// assert_eq!(cube(3), 27);
+/// ```
+///
+/// # Example (expiring, capacity-bounded entries)
+/// ```
+/// use once_cell::sync::Lazy;
+/// use std::sync::Mutex;
+/// use std::time::Duration;
+/// use memory_cache::{MemoryCache, cached};
+///
+/// cached! {
+///     #[ttl(Duration::from_secs(60)), capacity(1000)]
+///     fn double(x: u128) -> u128 = { x * 2 }
+/// }
+///
+/// assert_eq!(double(21), 42);
+/// ```
 #[macro_export]
 macro_rules! cached {
-    (fn $name:ident ($($arg:ident: $arg_type:ty), *) -> $ret:ty = $body:expr) => {
+    // Shared expansion for all the public arms below: `$cache_ctor` builds
+    // the static `MemoryCache`, and `$lifetime` is the value passed as the
+    // `insert` call's `lifetime` argument.
+    (@build
+        cache = $cache_ctor:expr;
+        lifetime = $lifetime:expr;
+        fn $name:ident ($($arg:ident: $arg_type:ty), *) -> $ret:ty = $body:expr
+    ) => {
         #[allow(unused_parens)]
         fn $name($($arg: $arg_type), *) -> $ret {
             // Static instance of `MemoryCache<A, B>`.
             static CACHE: Lazy<Mutex<MemoryCache<($($arg_type),*), $ret>>> =
-                Lazy::new(|| Mutex::new(MemoryCache::new()));
+                Lazy::new(|| Mutex::new($cache_ctor));
 
             // Create key.
             let key = ($($arg.clone()), *);
 
             // Acquires a mutex for check cached value.
-            let cache = CACHE.lock().unwrap();
+            let mut cache = CACHE.lock().unwrap();
 
             match cache.get(&key) {
                 Some(value) => value.clone(),
@@ -45,10 +100,42 @@ macro_rules! cached {
 
                     // Acquires a mutex for add/update cache.
                     let mut cache = CACHE.lock().unwrap();
-                    cache.insert(key, value, None);
+                    cache.insert(key, value, $lifetime);
                     value.clone()
                 }
             }
         }
     };
+    (fn $name:ident ($($arg:ident: $arg_type:ty), *) -> $ret:ty = $body:expr) => {
+        $crate::cached! {
+            @build
+            cache = MemoryCache::new();
+            lifetime = None;
+            fn $name ($($arg: $arg_type), *) -> $ret = $body
+        }
+    };
+    (#[ttl($ttl:expr)] fn $name:ident ($($arg:ident: $arg_type:ty), *) -> $ret:ty = $body:expr) => {
+        $crate::cached! {
+            @build
+            cache = MemoryCache::with_full_scan($ttl);
+            lifetime = Some($ttl);
+            fn $name ($($arg: $arg_type), *) -> $ret = $body
+        }
+    };
+    (#[capacity($capacity:expr)] fn $name:ident ($($arg:ident: $arg_type:ty), *) -> $ret:ty = $body:expr) => {
+        $crate::cached! {
+            @build
+            cache = MemoryCache::with_capacity($capacity);
+            lifetime = None;
+            fn $name ($($arg: $arg_type), *) -> $ret = $body
+        }
+    };
+    (#[ttl($ttl:expr), capacity($capacity:expr)] fn $name:ident ($($arg:ident: $arg_type:ty), *) -> $ret:ty = $body:expr) => {
+        $crate::cached! {
+            @build
+            cache = MemoryCache::with_full_scan_and_capacity($ttl, $capacity);
+            lifetime = Some($ttl);
+            fn $name ($($arg: $arg_type), *) -> $ret = $body
+        }
+    };
 }
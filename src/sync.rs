@@ -0,0 +1,279 @@
+use crate::MemoryCache;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Stops the background maintenance thread when the owning [`SyncMemoryCache`] is dropped.
+struct Maintenance {
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for Maintenance {
+    fn drop(&mut self) {
+        let (stopped, condvar) = &*self.stop;
+        *stopped.lock().unwrap() = true;
+        condvar.notify_one();
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// A thread-safe [`MemoryCache`] wrapper.
+///
+/// Expired entries are swept on a background thread on a fixed cadence,
+/// independent of how often the cache is called. [`SyncMemoryCache::get_or_insert`]
+/// is stampede-safe: if several threads miss the same key concurrently, only
+/// one of them runs the factory, and the others wait for and share its result.
+pub struct SyncMemoryCache<A, B> {
+    cache: Arc<Mutex<MemoryCache<A, B>>>,
+    in_flight: Mutex<HashMap<A, Arc<OnceLock<B>>>>,
+
+    /// Held only for its `Drop` impl, which stops the background thread.
+    #[allow(dead_code)]
+    maintenance: Maintenance,
+}
+
+impl<A, B> SyncMemoryCache<A, B>
+where
+    A: Hash + Eq + Clone + Send + 'static,
+    B: Send + 'static,
+{
+    /// Wraps `cache` for concurrent use, sweeping expired entries every
+    /// `maintenance_interval` on a dedicated background thread.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::{MemoryCache, SyncMemoryCache};
+    /// use std::time::Duration;
+    ///
+    /// let cache = SyncMemoryCache::new(MemoryCache::new(), Duration::from_secs(60));
+    ///
+    /// assert_eq!(cache.get_or_insert("key", || "Hello, World!", None), "Hello, World!");
+    /// ```
+    pub fn new(cache: MemoryCache<A, B>, maintenance_interval: Duration) -> Self {
+        let cache = Arc::new(Mutex::new(cache));
+        let stop = Arc::new((Mutex::new(false), Condvar::new()));
+
+        let thread = {
+            let cache = Arc::clone(&cache);
+            let stop = Arc::clone(&stop);
+
+            std::thread::spawn(move || {
+                let (stopped, condvar) = &*stop;
+                let mut stopped = stopped.lock().unwrap();
+
+                loop {
+                    let (guard, timeout) = condvar
+                        .wait_timeout(stopped, maintenance_interval)
+                        .unwrap();
+                    stopped = guard;
+
+                    if *stopped {
+                        return;
+                    }
+
+                    if timeout.timed_out() {
+                        cache.lock().unwrap().retain(|_, _| true);
+                    }
+                }
+            })
+        };
+
+        Self {
+            cache,
+            in_flight: Mutex::new(HashMap::new()),
+            maintenance: Maintenance {
+                stop,
+                thread: Some(thread),
+            },
+        }
+    }
+
+    /// Gets the value for `key`, computing and inserting it via `factory` on a miss.
+    ///
+    /// If several threads call this for the same missing `key` concurrently,
+    /// only one of them runs `factory` and inserts its result (with its own
+    /// `lifetime`) into the cache; the rest block until that result is ready
+    /// and just return a clone of it, instead of every caller recomputing and
+    /// reinserting the value with whatever `lifetime` they happened to pass.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_cache::{MemoryCache, SyncMemoryCache};
+    /// use std::time::Duration;
+    ///
+    /// let cache = SyncMemoryCache::new(MemoryCache::new(), Duration::from_secs(60));
+    ///
+    /// let value = cache.get_or_insert("key", || "Hello, World!", None);
+    ///
+    /// assert_eq!(value, "Hello, World!");
+    /// ```
+    pub fn get_or_insert<F>(&self, key: A, factory: F, lifetime: Option<Duration>) -> B
+    where
+        F: FnOnce() -> B,
+        B: Clone,
+    {
+        if let Some(value) = self.cache.lock().unwrap().get(&key) {
+            return value.clone();
+        }
+
+        let in_flight = {
+            let mut in_flight_keys = self.in_flight.lock().unwrap();
+
+            // Check again under the `in_flight` lock: another thread may have
+            // inserted the value and removed its marker since the check above.
+            if let Some(value) = self.cache.lock().unwrap().get(&key) {
+                return value.clone();
+            }
+
+            Arc::clone(
+                in_flight_keys
+                    .entry(key.clone())
+                    .or_insert_with(|| Arc::new(OnceLock::new())),
+            )
+        };
+
+        // `OnceLock::get_or_init` runs the closure on exactly one of the
+        // racing threads, so `ran_factory` tells us whether *this* call was
+        // the one that computed `value`, as opposed to one that merely waited
+        // for another thread's `OnceLock::get_or_init` to resolve.
+        let ran_factory = Cell::new(false);
+        let value = in_flight
+            .get_or_init(|| {
+                ran_factory.set(true);
+                factory()
+            })
+            .clone();
+
+        if ran_factory.get() {
+            self.cache
+                .lock()
+                .unwrap()
+                .insert(key.clone(), value.clone(), lifetime);
+
+            let mut in_flight_keys = self.in_flight.lock().unwrap();
+            if matches!(in_flight_keys.get(&key), Some(current) if Arc::ptr_eq(current, &in_flight))
+            {
+                in_flight_keys.remove(&key);
+            }
+        }
+
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Barrier;
+
+    #[test]
+    fn get_or_insert_does_not_reinsert_for_an_already_resolved_in_flight_key() {
+        // Arrange: simulate a caller arriving after another thread already
+        // resolved the in-flight marker for "key" but before it finished
+        // inserting into the cache.
+        let cache = SyncMemoryCache::new(MemoryCache::new(), Duration::from_secs(60));
+        let resolved = Arc::new(OnceLock::new());
+        resolved.set(42).unwrap();
+        cache
+            .in_flight
+            .lock()
+            .unwrap()
+            .insert("key", Arc::clone(&resolved));
+
+        let factory_ran = Cell::new(false);
+
+        // Act
+        let value = cache.get_or_insert(
+            "key",
+            || {
+                factory_ran.set(true);
+                0
+            },
+            Some(Duration::from_secs(0)),
+        );
+
+        // Assert: the shared value is returned without running our factory or
+        // inserting our own (already-expired) lifetime into the cache.
+        assert_eq!(value, 42);
+        assert!(!factory_ran.get());
+        assert!(cache.cache.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn get_or_insert_returns_cached_value_without_recomputing() {
+        // Arrange
+        let cache = SyncMemoryCache::new(MemoryCache::new(), Duration::from_secs(60));
+        let calls = AtomicUsize::new(0);
+
+        // Act
+        let first = cache.get_or_insert(
+            "key",
+            || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                1
+            },
+            None,
+        );
+        let second = cache.get_or_insert(
+            "key",
+            || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                2
+            },
+            None,
+        );
+
+        // Assert
+        assert_eq!(first, 1);
+        assert_eq!(second, 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn get_or_insert_collapses_concurrent_misses_into_one_computation() {
+        // Arrange
+        let cache = Arc::new(SyncMemoryCache::new(MemoryCache::new(), Duration::from_secs(60)));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(8));
+
+        // Act
+        let results: Vec<usize> = std::thread::scope(|scope| {
+            (0..8)
+                .map(|_| {
+                    let cache = Arc::clone(&cache);
+                    let calls = Arc::clone(&calls);
+                    let barrier = Arc::clone(&barrier);
+
+                    scope.spawn(move || {
+                        barrier.wait();
+
+                        cache.get_or_insert(
+                            "key",
+                            || {
+                                calls.fetch_add(1, Ordering::SeqCst);
+                                std::thread::sleep(Duration::from_millis(50));
+                                42
+                            },
+                            None,
+                        )
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+
+        // Assert
+        assert!(results.into_iter().all(|value| value == 42));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}